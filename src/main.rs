@@ -3,9 +3,13 @@ use image::ImageReader; // Load and manipulate images
 use image::RgbaImage; // Load and manipulate images
 use std::collections::HashSet; // Store unique colors
 use std::collections::HashMap; // Remap logic
-use std::io::{self, Write}; // Handles user input/output
+use std::io::{self, BufWriter, Write}; // Handles user input/output
 use std::path::Path; // Manage filesystem paths
+use std::fs::File;
 use rfd::FileDialog;
+use png::{BitDepth, ColorType, Encoder};
+use rayon::prelude::*;
+use indicatif::{ProgressBar, ProgressStyle};
 
 // Calculate luminance of pixels in the provided image
 fn brightness(pixel: &[u8; 4]) -> f64{
@@ -23,11 +27,45 @@ fn load_image(path: &Path) -> RgbaImage {
         .to_rgba8()
 }
 
+// Non-interactive entry point: `--scheme <path>` together with one or more
+// image paths (optionally with `--suffix <suffix>` and `--tolerance <n>`)
+// runs the scheme-file batch straight through, with no prompts at all — the
+// actual no-prompting script use case the scheme-file mode was meant for.
+struct CliArgs {
+    scheme: Option<std::path::PathBuf>,
+    suffix: Option<String>,
+    tolerance: Option<f64>,
+    paths: Vec<std::path::PathBuf>,
+}
+
+fn parse_cli_args() -> CliArgs {
+    let mut cli = CliArgs {
+        scheme: None,
+        suffix: None,
+        tolerance: None,
+        paths: Vec::new(),
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scheme" => cli.scheme = args.next().map(std::path::PathBuf::from),
+            "--suffix" => cli.suffix = args.next(),
+            "--tolerance" => cli.tolerance = args.next().and_then(|v| v.parse().ok()),
+            other => cli.paths.push(std::path::PathBuf::from(other)),
+        }
+    }
+
+    cli
+}
+
 fn prompt_mode() -> String {
     println!("\nChoose mode:");
     println!("1 - Change a single color by label");
     println!("2 - Change all defined colors");
-    print!("Enter 1 or 2: ");
+    println!("3 - Recolor along a gradient (highlight -> shadow)");
+    println!("4 - Inspect palette (read-only report, no remap)");
+    print!("Enter 1, 2, 3 or 4: ");
     io::stdout().flush().unwrap();
 
     let mut mode = String::new();
@@ -35,6 +73,102 @@ fn prompt_mode() -> String {
     mode.trim().to_string()
 }
 
+// Parse a color expression as either hex (`0x`/`#` prefixed, 6 or 8 digits)
+// or comma-separated RGBA, e.g. "0x1a1a1a", "#1a1a1aff", "120,80,60,255".
+fn parse_color(text: &str) -> Option<[u8; 4]> {
+    let text = text.trim();
+
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        return parse_hex_color(hex);
+    }
+    if let Some(hex) = text.strip_prefix('#') {
+        return parse_hex_color(hex);
+    }
+
+    let parts: Vec<u8> = text
+        .split(',')
+        .filter_map(|s| s.trim().parse::<u8>().ok())
+        .collect();
+    match parts.as_slice() {
+        [r, g, b, a] => Some([*r, *g, *b, *a]),
+        _ => None,
+    }
+}
+
+fn parse_hex_color(hex: &str) -> Option<[u8; 4]> {
+    match hex.len() {
+        6 => {
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            Some([r, g, b, 255])
+        }
+        8 => {
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            let a = u8::from_str_radix(&hex[6..8], 16).ok()?;
+            Some([r, g, b, a])
+        }
+        _ => None,
+    }
+}
+
+// Load a scheme file mapping `label = color` per line (blank lines and `#`
+// comments are skipped) into a label->target-color dict. This is
+// image-independent by design: each image has its own actual pixel value
+// per label, so `build_remap_for_image` combines this with a given image's
+// own `label_map` rather than baking in one image's literal colors.
+fn load_scheme_file(path: &Path, labels: &[&str]) -> HashMap<String, [u8; 4]> {
+    let contents = std::fs::read_to_string(path).expect("Failed to read scheme file");
+    let mut label_targets = HashMap::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((label, color)) = line.split_once('=') else {
+            eprintln!("Skipping malformed scheme line: {}", line);
+            continue;
+        };
+        let label = label.trim();
+        let color = color.trim();
+
+        if !labels.contains(&label) {
+            eprintln!("Unknown label in scheme file: {}", label);
+            continue;
+        }
+
+        match parse_color(color) {
+            Some(new_color) => {
+                label_targets.insert(label.to_string(), new_color);
+            }
+            None => eprintln!("Unparsable color in scheme file: {}", color),
+        }
+    }
+
+    label_targets
+}
+
+// Combine one image's own labeled colors with a shared label->target dict
+// (from a scheme file) into that image's old->new remap. Labels the dict
+// doesn't mention keep their original color, so fuzzy tolerance matching
+// still has every labeled color as a candidate.
+fn build_remap_for_image(
+    label_map: &HashMap<String, [u8; 4]>,
+    label_targets: &HashMap<String, [u8; 4]>,
+) -> HashMap<[u8; 4], [u8; 4]> {
+    label_map
+        .iter()
+        .map(|(label, &old_color)| {
+            let new_color = label_targets.get(label).copied().unwrap_or(old_color);
+            (old_color, new_color)
+        })
+        .collect()
+}
+
 fn extract_labeled_colors(image: &RgbaImage, labels: &[&str]) -> (Vec<[u8; 4]>, HashMap<String, [u8; 4]>) {
     let mut unique_pixels = HashSet::new();
 
@@ -55,6 +189,152 @@ fn extract_labeled_colors(image: &RgbaImage, labels: &[&str]) -> (Vec<[u8; 4]>,
     (sorted_pixels, label_map)
 }
 
+// Auto-generate a remap that recolors the whole brightness ramp produced by
+// `extract_labeled_colors` between two endpoint colors, so a sprite can be
+// retextured (e.g. to gold or steel) without entering every color by hand.
+fn gradient_remap(sorted_pixels: &[[u8; 4]], highlight: [u8; 4], shadow: [u8; 4]) -> HashMap<[u8; 4], [u8; 4]> {
+    let mut remap = HashMap::new();
+    let last = sorted_pixels.len().saturating_sub(1);
+
+    for (i, &pixel) in sorted_pixels.iter().enumerate() {
+        // Fully-transparent pixels are typically sprite background, not
+        // part of the tonal ramp — leave them untouched rather than
+        // lerping them into an opaque gradient color.
+        if pixel[3] == 0 {
+            remap.insert(pixel, pixel);
+            continue;
+        }
+
+        let t = if last == 0 { 0.0 } else { i as f64 / last as f64 };
+        let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        let new_color = [
+            lerp(highlight[0], shadow[0]),
+            lerp(highlight[1], shadow[1]),
+            lerp(highlight[2], shadow[2]),
+            lerp(highlight[3], shadow[3]),
+        ];
+        remap.insert(pixel, new_color);
+    }
+
+    remap
+}
+
+// Perceptually weighted squared distance between two RGBA colors, weighting
+// green the way `brightness` does for luminance.
+fn color_distance(a: &[u8; 4], b: &[u8; 4]) -> f64 {
+    let dr = a[0] as f64 - b[0] as f64;
+    let dg = a[1] as f64 - b[1] as f64;
+    let db = a[2] as f64 - b[2] as f64;
+    let da = a[3] as f64 - b[3] as f64;
+    2.0 * dr * dr + 4.0 * dg * dg + 3.0 * db * db + da * da
+}
+
+// Resolve a source pixel against `remap`, falling back to the nearest
+// labeled source color within `tolerance` when there's no exact match.
+// Resolutions are memoized in `cache` so each unique input color is only
+// ever matched once.
+fn resolve_color(
+    pixel: [u8; 4],
+    remap: &HashMap<[u8; 4], [u8; 4]>,
+    tolerance: f64,
+    cache: &mut HashMap<[u8; 4], [u8; 4]>,
+) -> [u8; 4] {
+    if let Some(&exact) = remap.get(&pixel) {
+        return exact;
+    }
+    if tolerance <= 0.0 {
+        return pixel;
+    }
+    if let Some(&cached) = cache.get(&pixel) {
+        return cached;
+    }
+
+    let mut best: Option<([u8; 4], f64)> = None;
+    for (&source, &target) in remap {
+        let distance = color_distance(&pixel, &source);
+        if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+            best = Some((target, distance));
+        }
+    }
+
+    let resolved = match best {
+        Some((target, distance)) if distance <= tolerance => target,
+        _ => pixel,
+    };
+    cache.insert(pixel, resolved);
+    resolved
+}
+
+// Count how many pixels use each color, with a chunks_exact(4) walk of the
+// same form `extract_labeled_colors` uses to find the unique set. This is a
+// separate pass over the pixels; it does not share that function's pass.
+fn color_histogram(image: &RgbaImage) -> HashMap<[u8; 4], usize> {
+    let mut histogram = HashMap::new();
+    for chunk in image.as_raw().chunks_exact(4) {
+        let pixel = [chunk[0], chunk[1], chunk[2], chunk[3]];
+        *histogram.entry(pixel).or_insert(0) += 1;
+    }
+    histogram
+}
+
+// Print a read-only report of an image's palette: dimensions, color type,
+// unique color count, and the brightness-sorted palette with each color's
+// label, hex value, RGBA tuple and pixel frequency.
+fn print_report(path: &Path, labels: &[&str]) {
+    let decoded = ImageReader::open(path)
+        .expect("Failed to open image")
+        .decode()
+        .expect("Failed to decode image");
+    let color_type = decoded.color();
+    let rgba_image = decoded.to_rgba8();
+
+    let (sorted_pixels, label_map) = extract_labeled_colors(&rgba_image, labels);
+    let histogram = color_histogram(&rgba_image);
+    let reverse_labels: HashMap<[u8; 4], String> = label_map
+        .iter()
+        .map(|(label, &color)| (color, label.clone()))
+        .collect();
+
+    println!("\n{}", path.display());
+    println!("  Dimensions: {}x{}", rgba_image.width(), rgba_image.height());
+    println!("  Color type: {:?}", color_type);
+    println!("  Unique colors: {}", sorted_pixels.len());
+
+    for pixel in &sorted_pixels {
+        let label = reverse_labels.get(pixel).map(String::as_str).unwrap_or("?");
+        let count = histogram.get(pixel).copied().unwrap_or(0);
+        println!(
+            "  {:<18} #{:02x}{:02x}{:02x}{:02x}  ({}, {}, {}, {})  x{}",
+            label, pixel[0], pixel[1], pixel[2], pixel[3], pixel[0], pixel[1], pixel[2], pixel[3], count
+        );
+    }
+
+    print!("\nWrite this palette as a scheme file? (y/n): ");
+    io::stdout().flush().unwrap();
+    let mut write_scheme = String::new();
+    io::stdin().read_line(&mut write_scheme).unwrap();
+    if write_scheme.trim().eq_ignore_ascii_case("y") {
+        print!("Enter path for scheme file: ");
+        io::stdout().flush().unwrap();
+        let mut scheme_path = String::new();
+        io::stdin().read_line(&mut scheme_path).unwrap();
+        write_scheme_file(Path::new(scheme_path.trim()), &sorted_pixels, &reverse_labels);
+    }
+}
+
+fn write_scheme_file(path: &Path, sorted_pixels: &[[u8; 4]], reverse_labels: &HashMap<[u8; 4], String>) {
+    let mut contents = String::new();
+    for pixel in sorted_pixels {
+        let label = reverse_labels.get(pixel).map(String::as_str).unwrap_or("?");
+        contents.push_str(&format!(
+            "{} = 0x{:02x}{:02x}{:02x}{:02x}\n",
+            label, pixel[0], pixel[1], pixel[2], pixel[3]
+        ));
+    }
+    std::fs::write(path, contents).expect("Failed to write scheme file");
+    println!("Scheme written to: {}", path.display());
+}
+
 fn remap_colors(mode: &str, _sorted_pixels: &[ [u8; 4] ], label_map: &HashMap<String, [u8; 4]>) -> HashMap<[u8; 4], [u8; 4]> {
     let mut remap = HashMap::new();
 
@@ -96,6 +376,15 @@ fn remap_colors(mode: &str, _sorted_pixels: &[ [u8; 4] ], label_map: &HashMap<St
                     eprintln!("Label not found.");
                 }
             }
+
+            // Every other labeled color maps to itself, so it's still a
+            // fuzzy-match candidate in `resolve_color`: an anti-aliased
+            // pixel near an untouched label snaps to that label (a no-op),
+            // rather than having its only candidate be the one changed
+            // label regardless of which labeled color it's actually closest to.
+            for &pixel in label_map.values() {
+                remap.entry(pixel).or_insert(pixel);
+            }
         }
         "2" => {
             for (label, &pixel) in label_map {
@@ -133,6 +422,124 @@ fn remap_colors(mode: &str, _sorted_pixels: &[ [u8; 4] ], label_map: &HashMap<St
     remap
 }
 
+// Write `image` as an indexed PNG when it has a small enough palette
+// (<=256 distinct colors), since this tool only ever deals with sprites
+// built from a handful of labeled colors. Falls back to a plain RGBA PNG
+// otherwise. `path` is expected to already carry a `.png` extension (see
+// `output_path`) since both branches here always write PNG bytes, even
+// when the source image was a jpg/bmp.
+fn save_indexed_png(image: &RgbaImage, path: &Path) {
+    let mut palette: Vec<[u8; 4]> = Vec::new();
+    let mut index_of: HashMap<[u8; 4], u8> = HashMap::new();
+
+    for pixel in image.pixels() {
+        let rgba = pixel.0;
+        if let std::collections::hash_map::Entry::Vacant(entry) = index_of.entry(rgba) {
+            if palette.len() >= 256 {
+                image.save_with_format(path, image::ImageFormat::Png)
+                    .expect("Failed to save output image");
+                return;
+            }
+            entry.insert(palette.len() as u8);
+            palette.push(rgba);
+        }
+    }
+
+    let indices: Vec<u8> = image.pixels().map(|pixel| index_of[&pixel.0]).collect();
+    let rgb_palette: Vec<u8> = palette.iter().flat_map(|p| [p[0], p[1], p[2]]).collect();
+    let trns: Vec<u8> = palette.iter().map(|p| p[3]).collect();
+
+    let file = File::create(path).expect("Failed to create output file");
+    let writer = BufWriter::new(file);
+    let mut encoder = Encoder::new(writer, image.width(), image.height());
+    encoder.set_color(ColorType::Indexed);
+    encoder.set_depth(BitDepth::Eight);
+    encoder.set_palette(rgb_palette);
+    encoder.set_trns(trns);
+
+    let mut writer = encoder.write_header().expect("Failed to write PNG header");
+    writer
+        .write_image_data(&indices)
+        .expect("Failed to write indexed pixel data");
+}
+
+// Apply `remap` (with fuzzy fallback via `tolerance`) to a single decoded
+// image and write it to disk.
+fn apply_and_save(rgba_image: &RgbaImage, remap: &HashMap<[u8; 4], [u8; 4]>, tolerance: f64, path: &Path, suffix: &str) {
+    let mut resolved_cache: HashMap<[u8; 4], [u8; 4]> = HashMap::new();
+
+    let mut output = RgbaImage::new(rgba_image.width(), rgba_image.height());
+    for (x, y, pixel) in rgba_image.enumerate_pixels() {
+        let new_rgba = resolve_color(pixel.0, remap, tolerance, &mut resolved_cache);
+        output.put_pixel(x, y, image::Rgba(new_rgba));
+    }
+
+    let save_path = output_path(path, suffix);
+    save_indexed_png(&output, &save_path);
+    println!("✅ saved: {}", save_path.display());
+}
+
+fn new_progress_bar(len: u64) -> ProgressBar {
+    let progress = ProgressBar::new(len);
+    progress.set_style(ProgressStyle::with_template("{bar:40.cyan/blue} {pos}/{len} {msg}").unwrap());
+    progress
+}
+
+// Shared-palette batch path: `label_targets` is the same for every image
+// (from a scheme file), but each image's actual pixel values differ, so
+// `build_remap_for_image` recomputes the real old->new remap per image.
+// This only does pixel substitution and I/O inside the parallel closure —
+// no prompting — so it's safe to run across the whole batch at once.
+fn run_labeled_batch(paths: &[std::path::PathBuf], labels: &[&str], label_targets: &HashMap<String, [u8; 4]>, tolerance: f64, suffix: &str) {
+    let progress = new_progress_bar(paths.len() as u64);
+    paths.par_iter().for_each(|path| {
+        let rgba_image = load_image(path);
+        let (_, label_map) = extract_labeled_colors(&rgba_image, labels);
+        let remap = build_remap_for_image(&label_map, label_targets);
+        apply_and_save(&rgba_image, &remap, tolerance, path, suffix);
+        progress.inc(1);
+    });
+    progress.finish_with_message("done");
+}
+
+// Same idea as `run_labeled_batch`, but for the gradient mode: the shared
+// input is the highlight/shadow endpoints, and each image's own brightness
+// ramp (from `extract_labeled_colors`) determines its remap.
+fn run_gradient_batch(paths: &[std::path::PathBuf], labels: &[&str], highlight: [u8; 4], shadow: [u8; 4], tolerance: f64, suffix: &str) {
+    let progress = new_progress_bar(paths.len() as u64);
+    paths.par_iter().for_each(|path| {
+        let rgba_image = load_image(path);
+        let (sorted_pixels, _) = extract_labeled_colors(&rgba_image, labels);
+        let remap = gradient_remap(&sorted_pixels, highlight, shadow);
+        apply_and_save(&rgba_image, &remap, tolerance, path, suffix);
+        progress.inc(1);
+    });
+    progress.finish_with_message("done");
+}
+
+// Modes 1 and 2 (without a scheme file) prompt the user per label, and
+// those prompts are only meaningful against one image's own palette at a
+// time, so this stays a serial per-image loop rather than a shared-remap
+// parallel batch.
+fn run_serial_interactive(paths: &[std::path::PathBuf], labels: &[&str], mode: &str, tolerance: f64, suffix: &str) {
+    for path in paths {
+        let rgba_image = load_image(path);
+        let (sorted_pixels, label_map) = extract_labeled_colors(&rgba_image, labels);
+        println!("\n{}", path.display());
+        let remap = remap_colors(mode, &sorted_pixels, &label_map);
+        apply_and_save(&rgba_image, &remap, tolerance, path, suffix);
+    }
+}
+
+// Output is always written as PNG (see `save_indexed_png`), regardless of
+// the source format, so the path's extension is forced to `.png` rather
+// than inherited from the input — a jpg/bmp input re-encoded under its
+// original extension would otherwise contain PNG bytes.
+fn output_path(path: &Path, suffix: &str) -> std::path::PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("output");
+    path.with_file_name(format!("{}{}.png", stem, suffix))
+}
+
 fn main() {
     let labels = [
         "white",
@@ -145,40 +552,89 @@ fn main() {
         "black",
     ];
 
-    let image_paths: Vec<std::path::PathBuf> = FileDialog::new()
-        .add_filter("Image files", &["png", "jpg", "jpeg", "bmp"])
-        .set_title("Select one or more images to modify")
-        .pick_files()
-        .unwrap_or_else(|| {
-            println!("No files selected.");
+    let cli = parse_cli_args();
+    if let Some(scheme_path) = &cli.scheme {
+        if cli.paths.is_empty() {
+            eprintln!("--scheme requires at least one image path argument");
             std::process::exit(1);
-        });
+        }
+        let label_targets = load_scheme_file(scheme_path, &labels);
+        let tolerance = cli.tolerance.unwrap_or(0.0);
+        let suffix = cli.suffix.as_deref().unwrap_or("_remapped");
+        run_labeled_batch(&cli.paths, &labels, &label_targets, tolerance, suffix);
+        return;
+    }
 
-    for path in image_paths {
-        let rgba_image = load_image(&path);
-        let (sorted_pixels, label_map) = extract_labeled_colors(&rgba_image, &labels);
-        let mode = prompt_mode();
-        let remap = remap_colors(&mode, &sorted_pixels, &label_map);
+    let image_paths: Vec<std::path::PathBuf> = if !cli.paths.is_empty() {
+        cli.paths
+    } else {
+        FileDialog::new()
+            .add_filter("Image files", &["png", "jpg", "jpeg", "bmp"])
+            .set_title("Select one or more images to modify")
+            .pick_files()
+            .unwrap_or_else(|| {
+                println!("No files selected.");
+                std::process::exit(1);
+            })
+    };
 
-        let mut output = RgbaImage::new(rgba_image.width(), rgba_image.height());
-        for (x, y, pixel) in rgba_image.enumerate_pixels() {
-            let rgba = pixel.0;
-            let new_rgba = remap.get(&rgba).unwrap_or(&rgba);
-            output.put_pixel(x, y, image::Rgba(*new_rgba));
+    let mode = prompt_mode();
+    if mode == "4" {
+        for path in &image_paths {
+            print_report(path, &labels);
         }
+        return;
+    }
 
-        print!("\nEnter filename for modified file: ");
-        io::stdout().flush().unwrap();
-        let mut output_name = String::new();
-        io::stdin().read_line(&mut output_name).unwrap();
-        let output_name = output_name.trim();
+    print!("\nColor match tolerance for anti-aliased edges (0 for exact match only): ");
+    io::stdout().flush().unwrap();
+    let mut tolerance_input = String::new();
+    io::stdin().read_line(&mut tolerance_input).unwrap();
+    let tolerance: f64 = tolerance_input.trim().parse().unwrap_or(0.0);
 
-        let save_path = path
-            .parent()
-            .unwrap_or_else(|| Path::new("."))
-            .join(output_name);
-        output.save(&save_path).expect("Failed to save output image");
+    print!("Suffix to append to each output filename (e.g. _remapped): ");
+    io::stdout().flush().unwrap();
+    let mut suffix = String::new();
+    io::stdin().read_line(&mut suffix).unwrap();
+    let suffix = suffix.trim();
+
+    match mode.as_str() {
+        "3" => {
+            print!("Enter highlight color (hex or R,G,B,A): ");
+            io::stdout().flush().unwrap();
+            let mut highlight_input = String::new();
+            io::stdin().read_line(&mut highlight_input).unwrap();
+
+            print!("Enter shadow color (hex or R,G,B,A): ");
+            io::stdout().flush().unwrap();
+            let mut shadow_input = String::new();
+            io::stdin().read_line(&mut shadow_input).unwrap();
+
+            match (parse_color(&highlight_input), parse_color(&shadow_input)) {
+                (Some(highlight), Some(shadow)) => {
+                    run_gradient_batch(&image_paths, &labels, highlight, shadow, tolerance, suffix);
+                }
+                _ => eprintln!("Invalid gradient colors. Skipping remap."),
+            }
+        }
+        "2" => {
+            print!("Load colors from a scheme file instead of prompting per image? (y/n): ");
+            io::stdout().flush().unwrap();
+            let mut use_scheme = String::new();
+            io::stdin().read_line(&mut use_scheme).unwrap();
 
-        println!("âœ… Image saved as: {}", save_path.display());
+            if use_scheme.trim().eq_ignore_ascii_case("y") {
+                print!("Enter path to scheme file: ");
+                io::stdout().flush().unwrap();
+                let mut scheme_path = String::new();
+                io::stdin().read_line(&mut scheme_path).unwrap();
+                let label_targets = load_scheme_file(Path::new(scheme_path.trim()), &labels);
+                run_labeled_batch(&image_paths, &labels, &label_targets, tolerance, suffix);
+            } else {
+                run_serial_interactive(&image_paths, &labels, &mode, tolerance, suffix);
+            }
+        }
+        "1" => run_serial_interactive(&image_paths, &labels, &mode, tolerance, suffix),
+        _ => eprintln!("Invalid mode selected."),
     }
 }